@@ -1,6 +1,9 @@
 use core::cell::{Cell, RefCell};
 use core::pin::Pin;
 use core::time::Duration;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
 use futures_util::future::{select, Either};
 use futures_util::stream::{FuturesUnordered, Stream, StreamExt};
@@ -19,10 +22,19 @@ use crate::types::LocalBoxedFuture;
 
 pub struct Context<A> {
     state: Cell<ActorState>,
-    interval_queue: RefCell<Slab<IntervalMessage<A>>>,
-    delay_queue: RefCell<Slab<ActorMessage<A>>>,
+    // each entry also carries the `oneshot::Sender` half of that timer's own
+    // restart-cancel channel, so a restart can stop the background `A::spawn` task
+    // driving it instead of leaking it (see `Drop`'s `RestartStrategy::Restart` arm).
+    interval_queue: RefCell<Slab<(IntervalMessage<A>, oneshot::Sender<()>)>>,
+    delay_queue: RefCell<Slab<(ActorMessage<A>, oneshot::Sender<()>)>>,
+    // one entry per in-flight `run_blocking` job, holding only its restart-cancel
+    // sender: the job's result lives in the background task's own stack frame, not
+    // here, so there's no payload to store or replay.
+    blocking_queue: RefCell<Slab<oneshot::Sender<()>>>,
     tx: WeakAddr<A>,
     rx: RefCell<Receiver<ActorMessage<A>>>,
+    // deadline set by `stop_timeout`, after which `run` abandons the graceful drain.
+    stop_deadline: Cell<Option<std::time::Instant>>,
 }
 
 /// a join handle can be used to cancel a spawned async task like interval closure and stream
@@ -37,29 +49,338 @@ impl ContextJoinHandle {
     }
 }
 
+// whether an interval re-arms relative to when its last tick was delivered to the
+// mailbox (drifting once a slow handler backs the mailbox up) or relative to an absolute deadline that advances by a fixed `dur`
+// regardless of handling time.
+enum IntervalSchedule {
+    FixedDelay,
+    FixedRate(MissedTickBehavior),
+}
+
+/// governs what a fixed-rate interval (see [`Context::run_interval_at`]) does when a
+/// tick is missed entirely because handling the previous one ran longer than the
+/// period. Mirrors `tokio::time::MissedTickBehavior`.
+#[derive(Clone, Copy)]
+pub enum MissedTickBehavior {
+    /// fire every missed tick back-to-back, catching the schedule up to now before
+    /// parking again.
+    Burst,
+    /// drop the missed ticks and reset the schedule relative to now.
+    Delay,
+    /// drop the missed ticks but keep future ticks phase-aligned to the original
+    /// start, as if they had silently fired on schedule.
+    Skip,
+}
+
+impl Default for MissedTickBehavior {
+    fn default() -> Self {
+        MissedTickBehavior::Burst
+    }
+}
+
+// advances a fixed-rate interval's deadline past `now`, applying `missed`'s policy
+// and sending any extra `IntervalToken`s a `Burst` schedule owes along the way.
+// returns `None` if the actor's mailbox is gone and the interval task should exit.
+async fn next_fixed_rate_deadline<A: Actor>(
+    deadline: std::time::Instant,
+    dur: Duration,
+    missed: MissedTickBehavior,
+    weak_tx: &WeakAddr<A>,
+    token: usize,
+) -> Option<std::time::Instant> {
+    let now = std::time::Instant::now();
+
+    match missed {
+        MissedTickBehavior::Burst => {
+            let mut next = deadline + dur;
+            while next <= now {
+                if weak_tx
+                    ._send(ActorMessage::IntervalToken(token))
+                    .await
+                    .is_err()
+                {
+                    return None;
+                }
+                next += dur;
+            }
+            Some(next)
+        }
+        MissedTickBehavior::Delay => {
+            let next = deadline + dur;
+            Some(if next <= now { now + dur } else { next })
+        }
+        MissedTickBehavior::Skip => {
+            let mut next = deadline + dur;
+            while next <= now {
+                next += dur;
+            }
+            Some(next)
+        }
+    }
+}
+
+// outcome of racing `Receiver::recv` against an optional drain deadline.
+enum Drain<A> {
+    Msg(ActorMessage<A>),
+    Closed,
+    TimedOut,
+}
+
+// recv's unconditionally when `deadline` is `None`; otherwise races the recv
+// against the deadline, as used by `run`'s drain loop under `stop_timeout`.
+async fn recv_with_deadline<A: Actor>(
+    rx: &mut Receiver<ActorMessage<A>>,
+    deadline: Option<std::time::Instant>,
+) -> Drain<A> {
+    match deadline {
+        Some(deadline) => {
+            let sleep = A::sleep(deadline.saturating_duration_since(std::time::Instant::now()));
+            match select(rx.recv(), sleep).await {
+                Either::Left((Some(msg), _)) => Drain::Msg(msg),
+                Either::Left((None, _)) => Drain::Closed,
+                Either::Right(_) => Drain::TimedOut,
+            }
+        }
+        None => match rx.recv().await {
+            Some(msg) => Drain::Msg(msg),
+            None => Drain::Closed,
+        },
+    }
+}
+
+// outcome of racing `Context::handle_message` against an optional drain deadline.
+enum Processed {
+    Done(bool),
+    TimedOut,
+}
+
+// handles `msg` unconditionally when `deadline` is `None`; otherwise races the
+// handling itself (not just the idle wait before it) against the deadline, so a
+// deep backlog or a single wedged handler can't hang a `stop_timeout` drain.
+// on `TimedOut` the in-flight `handle_message` future is dropped, abandoning
+// whatever step it was on, along with any concurrent futures it left in `fut`.
+async fn handle_with_deadline<A: Actor>(
+    ctx: &mut Context<A>,
+    msg: ActorMessage<A>,
+    actor: &mut A,
+    cache_mut: &mut Option<MessageObject<A>>,
+    cache_ref: &mut Vec<MessageObject<A>>,
+    fut: &mut FuturesUnordered<LocalBoxedFuture<'static, ()>>,
+    drop_notify: &mut Option<oneshot::Sender<()>>,
+    deadline: Option<std::time::Instant>,
+) -> Processed {
+    match deadline {
+        Some(deadline) => {
+            let handle = ctx.handle_message(msg, actor, cache_mut, cache_ref, fut, drop_notify);
+            let sleep = A::sleep(deadline.saturating_duration_since(std::time::Instant::now()));
+            let is_force_stop = match select(Box::pin(handle), sleep).await {
+                Either::Left((is_force_stop, _)) => Some(is_force_stop),
+                Either::Right(_) => None,
+            };
+            match is_force_stop {
+                Some(is_force_stop) => Processed::Done(is_force_stop),
+                None => {
+                    // the abandoned futures still borrow `actor` and `ctx`: drop them now.
+                    *fut = FuturesUnordered::new();
+                    Processed::TimedOut
+                }
+            }
+        }
+        None => Processed::Done(
+            ctx.handle_message(msg, actor, cache_mut, cache_ref, fut, drop_notify)
+                .await,
+        ),
+    }
+}
+
+// abandons whatever is cached or still queued, releasing any caller awaiting
+// shutdown now instead of waiting for it.
+fn abandon<A: Actor>(
+    cache_mut: &mut Option<MessageObject<A>>,
+    cache_ref: &mut Vec<MessageObject<A>>,
+    drop_notify: &mut Option<oneshot::Sender<()>>,
+) {
+    *cache_mut = None;
+    cache_ref.clear();
+    if let Some(tx) = drop_notify.take() {
+        let _ = tx.send(());
+    }
+}
+
+// races `Context::try_handle_concurrent` itself against an optional drain deadline,
+// not just the idle wait around it — a single wedged concurrent (`Ref`) handler is
+// exactly the kind of "single misbehaving message" `stop_timeout` is meant to bound.
+// returns `true` if the deadline won the race; the in-flight concurrent futures are
+// simply dropped, abandoning whatever step they were on.
+async fn try_handle_concurrent_with_deadline<A: Actor>(
+    ctx: &Context<A>,
+    actor: &A,
+    cache_ref: &mut Vec<MessageObject<A>>,
+    fut: &mut FuturesUnordered<LocalBoxedFuture<'static, ()>>,
+    deadline: Option<std::time::Instant>,
+) -> bool {
+    match deadline {
+        Some(deadline) => {
+            let drain = ctx.try_handle_concurrent(actor, cache_ref, fut);
+            let sleep = A::sleep(deadline.saturating_duration_since(std::time::Instant::now()));
+            let timed_out = matches!(select(Box::pin(drain), sleep).await, Either::Right(_));
+            if timed_out {
+                // the abandoned futures still borrow `actor` and `ctx`: drop them now.
+                *fut = FuturesUnordered::new();
+            }
+            timed_out
+        }
+        None => {
+            ctx.try_handle_concurrent(actor, cache_ref, fut).await;
+            false
+        }
+    }
+}
+
+/// process-global topic registry backing [`Context::subscribe`] and [`publish`].
+mod broadcast {
+    use super::*;
+
+    // keyed by `TypeId::of::<M>()`, each entry is a `Slab<Box<dyn Subscriber<M> + Send>>`
+    // downcast through `Any` so subscribers of unrelated message types can share one
+    // registry.
+    struct Registry(HashMap<TypeId, Box<dyn Any + Send>>);
+
+    static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+
+    // `REGISTRY` is a process-global `OnceLock`, reachable from `subscribe`/`publish`
+    // on any actor, on any thread or `LocalSet`, so a stored subscriber must actually
+    // be `Send` rather than just asserted to be — hence the supertrait instead of an
+    // `unsafe impl Send for Registry`.
+    pub(super) trait Subscriber<M>: 'static + Send {
+        fn alive(&self) -> bool;
+        fn publish(&self, msg: M) -> LocalBoxedFuture<'static, ()>;
+    }
+
+    impl<A, M> Subscriber<M> for WeakAddr<A>
+    where
+        A: Actor + Handler<M>,
+        M: Message + 'static,
+    {
+        fn alive(&self) -> bool {
+            self.upgrade().is_some()
+        }
+
+        fn publish(&self, msg: M) -> LocalBoxedFuture<'static, ()> {
+            let weak = self.clone();
+            Box::pin(async move {
+                let msg = MessageObject::new(msg, None);
+                let _ = weak._send(ActorMessage::Ref(msg)).await;
+            })
+        }
+    }
+
+    // a locked handle onto the `Slab<Box<dyn Subscriber<M> + Send>>` for one message
+    // type, created (if absent) on first access.
+    pub(super) struct Subscribers<M> {
+        guard: std::sync::MutexGuard<'static, Registry>,
+        _m: core::marker::PhantomData<M>,
+    }
+
+    impl<M: 'static> Subscribers<M> {
+        fn slab(&mut self) -> &mut Slab<Box<dyn Subscriber<M> + Send>> {
+            self.guard
+                .0
+                .entry(TypeId::of::<M>())
+                .or_insert_with(|| Box::new(Slab::<Box<dyn Subscriber<M> + Send>>::new()))
+                .downcast_mut::<Slab<Box<dyn Subscriber<M> + Send>>>()
+                .expect("broadcast registry corrupted: TypeId collided with a different Slab type")
+        }
+
+        pub(super) fn insert(&mut self, sub: Box<dyn Subscriber<M> + Send>) -> usize {
+            self.slab().insert(sub)
+        }
+
+        pub(super) fn contains(&mut self, token: usize) -> bool {
+            self.slab().contains(token)
+        }
+
+        pub(super) fn remove(&mut self, token: usize) {
+            self.slab().remove(token);
+        }
+    }
+
+    pub(super) fn subscribers<M: 'static>() -> Subscribers<M> {
+        let guard = REGISTRY
+            .get_or_init(|| Mutex::new(Registry(HashMap::new())))
+            .lock()
+            .unwrap();
+
+        Subscribers {
+            guard,
+            _m: core::marker::PhantomData,
+        }
+    }
+
+    pub(super) async fn publish<M>(msg: M)
+    where
+        M: Message + Clone + 'static,
+    {
+        let futs: Vec<_> = {
+            let mut subs = subscribers::<M>();
+            let slab = subs.slab();
+            // lazily garbage-collect subscribers whose actor has gone away.
+            slab.retain(|_, s| s.alive());
+            slab.iter().map(|(_, s)| s.publish(msg.clone())).collect()
+        };
+
+        for fut in futs {
+            fut.await;
+        }
+    }
+}
+
+/// publish `msg` to every actor currently subscribed to `M` via
+/// [`Context::subscribe`]. Each subscriber gets its own clone, dispatched through
+/// `Handler::handle` the same as any other concurrent message.
+pub async fn publish<M>(msg: M)
+where
+    M: Message + Clone + 'static,
+{
+    broadcast::publish(msg).await;
+}
+
 impl<A: Actor> Context<A> {
     pub(crate) fn new(tx: WeakAddr<A>, rx: Receiver<ActorMessage<A>>) -> Self {
         Context {
             state: Cell::new(ActorState::Stop),
             interval_queue: RefCell::new(Slab::with_capacity(8)),
             delay_queue: RefCell::new(Slab::with_capacity(CHANNEL_CAP)),
+            blocking_queue: RefCell::new(Slab::with_capacity(4)),
             tx,
             rx: RefCell::new(rx),
+            stop_deadline: Cell::new(None),
         }
     }
 
     /// run interval concurrent closure on context. `Handler::handle` will be called.
+    ///
+    /// the schedule is fixed-delay: each tick is `dur` after the previous one was
+    /// *delivered to the mailbox*, so a slow handler that backs the mailbox up drifts
+    /// the cadence. Use [`run_interval_at`] for a
+    /// fixed-rate schedule instead.
+    ///
+    /// [`run_interval_at`]: Self::run_interval_at
     pub fn run_interval<F>(&self, dur: Duration, f: F) -> ContextJoinHandle
     where
         F: for<'a> FnOnce(&'a A, &'a Context<A>) -> LocalBoxedFuture<'a, ()> + Clone + 'static,
     {
         let msg = FunctionMessage::<F, ()>::new(f);
         let msg = IntervalMessage::Ref(Box::new(msg));
-        self.interval(dur, msg)
+        self.interval(dur, msg, IntervalSchedule::FixedDelay)
     }
 
     /// run interval exclusive closure on context. `Handler::handle_wait` will be called.
     /// If `Handler::handle_wait` is not override `Handler::handle` will be called as fallback.
+    ///
+    /// see [`run_interval`](Self::run_interval) for the fixed-delay vs fixed-rate
+    /// distinction; use [`run_wait_interval_at`](Self::run_wait_interval_at) for a
+    /// fixed-rate schedule.
     pub fn run_wait_interval<F>(&self, dur: Duration, f: F) -> ContextJoinHandle
     where
         F: for<'a> FnOnce(&'a mut A, &'a mut Context<A>) -> LocalBoxedFuture<'a, ()>
@@ -68,7 +389,49 @@ impl<A: Actor> Context<A> {
     {
         let msg = FunctionMutMessage::<F, ()>::new(f);
         let msg = IntervalMessage::Mut(Box::new(msg));
-        self.interval(dur, msg)
+        self.interval(dur, msg, IntervalSchedule::FixedDelay)
+    }
+
+    /// run interval concurrent closure on context on a fixed-rate schedule.
+    /// `Handler::handle` will be called.
+    ///
+    /// unlike [`run_interval`](Self::run_interval), the period is measured against an
+    /// absolute deadline that advances by `dur` regardless of how long handling took,
+    /// so the cadence does not drift under a slow handler. `missed` controls what
+    /// happens when a tick is missed entirely because handling ran longer than `dur`.
+    pub fn run_interval_at<F>(
+        &self,
+        dur: Duration,
+        missed: MissedTickBehavior,
+        f: F,
+    ) -> ContextJoinHandle
+    where
+        F: for<'a> FnOnce(&'a A, &'a Context<A>) -> LocalBoxedFuture<'a, ()> + Clone + 'static,
+    {
+        let msg = FunctionMessage::<F, ()>::new(f);
+        let msg = IntervalMessage::Ref(Box::new(msg));
+        self.interval(dur, msg, IntervalSchedule::FixedRate(missed))
+    }
+
+    /// run interval exclusive closure on context on a fixed-rate schedule.
+    /// `Handler::handle_wait` will be called.
+    /// If `Handler::handle_wait` is not override `Handler::handle` will be called as fallback.
+    ///
+    /// see [`run_interval_at`](Self::run_interval_at) for the fixed-rate schedule.
+    pub fn run_wait_interval_at<F>(
+        &self,
+        dur: Duration,
+        missed: MissedTickBehavior,
+        f: F,
+    ) -> ContextJoinHandle
+    where
+        F: for<'a> FnOnce(&'a mut A, &'a mut Context<A>) -> LocalBoxedFuture<'a, ()>
+            + Clone
+            + 'static,
+    {
+        let msg = FunctionMutMessage::<F, ()>::new(f);
+        let msg = IntervalMessage::Mut(Box::new(msg));
+        self.interval(dur, msg, IntervalSchedule::FixedRate(missed))
     }
 
     /// run concurrent closure on context after given duration. `Handler::handle` will be called.
@@ -93,6 +456,56 @@ impl<A: Actor> Context<A> {
         self.later(dur, ActorMessage::Mut(msg))
     }
 
+    /// run a blocking or CPU-bound `job` on a dedicated blocking thread pool and
+    /// deliver its result back onto the actor thread, where `then` runs through the
+    /// normal `Handler::handle_wait` path with full `&mut A` access.
+    ///
+    /// `job` and its result `T` cross the thread boundary, but `then` itself only
+    /// ever runs on the actor's own thread, so the `!Send` actor state is never
+    /// touched from the blocking pool.
+    pub fn run_blocking<F, T, C>(&self, job: F, then: C) -> ContextJoinHandle
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+        C: for<'a> FnOnce(T, &'a mut A, &'a mut Context<A>) -> LocalBoxedFuture<'a, ()> + 'static,
+    {
+        // see `interval`'s `restart_cancel` for why this exists: a restart resets the
+        // actor to a clean slate, and a blocking job that outlives the panic must not
+        // be able to deliver a result computed under the old, pre-restart state into
+        // it.
+        let (restart_cancel, restart_rx) = oneshot::channel();
+        let token = self.blocking_queue.borrow_mut().insert(restart_cancel);
+
+        let weak_tx = self.tx.clone();
+        let (tx_cancel, rx_cancel) = oneshot::channel();
+
+        A::spawn(async move {
+            let result = match select(select(rx_cancel, restart_rx), A::spawn_blocking(job)).await
+            {
+                Either::Left((Either::Left((Ok(_), _)), _)) => return,
+                Either::Left((Either::Left((Err(_), _)), job)) => job.await,
+                // actor is restarting: the slab entry is already gone, drop the result.
+                Either::Left((Either::Right(_), _)) => return,
+                Either::Right((result, _)) => result,
+            };
+
+            let msg = FunctionMutMessage::new(move |actor: &mut A, ctx: &mut Context<A>| {
+                // if a restart raced this job to completion and drained the slab
+                // entry first, the result was computed under state the restarted
+                // actor never had: drop it instead of calling `then`.
+                if ctx.blocking_queue.borrow_mut().try_remove(token).is_some() {
+                    then(result, actor, ctx)
+                } else {
+                    Box::pin(async {})
+                }
+            });
+            let msg = MessageObject::new(msg, None);
+            let _ = weak_tx._send(ActorMessage::Mut(msg)).await;
+        });
+
+        ContextJoinHandle { handle: tx_cancel }
+    }
+
     /// stop the context. It would end the actor gracefully by draining all remaining message in
     /// queue.
     ///
@@ -102,6 +515,20 @@ impl<A: Actor> Context<A> {
         self.state.set(ActorState::StopGraceful);
     }
 
+    /// stop the context the same way [`stop`](Self::stop) does, but bound the
+    /// graceful drain to `dur`. If the remaining queued messages (and any cached
+    /// `cache_ref`/`cache_mut`) are not fully handled before the deadline, `run`
+    /// abandons them, skips straight to `actor.on_stop`, and releases any caller
+    /// awaiting shutdown.
+    ///
+    /// this keeps a single slow or wedged handler from hanging orderly teardown
+    /// indefinitely, which matters for supervised restarts and process exit.
+    pub fn stop_timeout(&self, dur: Duration) {
+        self.stop();
+        self.stop_deadline
+            .set(Some(std::time::Instant::now() + dur));
+    }
+
     /// get the address of actor from context.
     pub fn address(&self) -> Option<Addr<A>> {
         self.tx.upgrade()
@@ -215,64 +642,205 @@ impl<A: Actor> Context<A> {
         ContextJoinHandle { handle: tx_cancel }
     }
 
-    fn interval(&self, dur: Duration, msg: IntervalMessage<A>) -> ContextJoinHandle {
-        let token = self.interval_queue.borrow_mut().insert(msg);
+    /// subscribe this actor to messages of type `M` published anywhere via
+    /// [`publish`]. Multiple actors, including multiple instances of the same type,
+    /// can subscribe to the same `M`.
+    ///
+    /// each publish is treated as a concurrent message and dispatched through
+    /// `Handler::handle`, same as [`add_stream`](Self::add_stream). Returns a
+    /// `ContextJoinHandle` that cancels the subscription the same way a stream or
+    /// interval is cancelled.
+    pub fn subscribe<M>(&self) -> ContextJoinHandle
+    where
+        A: Handler<M>,
+        M: Message + Clone + 'static,
+    {
+        let weak_tx = self.tx.clone();
+        let (tx_cancel, rx_cancel) = oneshot::channel();
+
+        let token = broadcast::subscribers::<M>().insert(Box::new(weak_tx));
+
+        A::spawn(async move {
+            match rx_cancel.await {
+                // the join handle was explicitly cancelled: drop the subscription.
+                Ok(_) => {
+                    let mut subs = broadcast::subscribers::<M>();
+                    if subs.contains(token) {
+                        subs.remove(token);
+                    }
+                }
+                // the join handle was merely dropped: keep the subscription alive,
+                // same as a dropped `stream`/`interval` handle never cancels it.
+                Err(_) => {}
+            }
+        });
+
+        ContextJoinHandle { handle: tx_cancel }
+    }
+
+    fn interval(
+        &self,
+        dur: Duration,
+        msg: IntervalMessage<A>,
+        schedule: IntervalSchedule,
+    ) -> ContextJoinHandle {
+        // a restart tears down the actor without re-running `on_start`, so any
+        // interval it armed would otherwise keep ticking into a mailbox nobody is
+        // re-arming; `restart_cancel` lives in the slab next to the message so
+        // `Drop`'s `RestartStrategy::Restart` arm can stop this task instead of
+        // leaking it.
+        let (restart_cancel, mut restart_rx) = oneshot::channel();
+        let token = self
+            .interval_queue
+            .borrow_mut()
+            .insert((msg, restart_cancel));
 
         let weak_tx = self.tx.clone();
         let (tx_cancel, mut rx_cancel) = oneshot::channel();
 
-        A::spawn(async move {
-            let mut sleep = A::sleep(dur);
-            loop {
-                match select(&mut rx_cancel, &mut sleep).await {
-                    // join handle notify to cancel.
-                    Either::Left((Ok(_), _)) => {
-                        let _ = weak_tx
-                            ._send(ActorMessage::IntervalTokenCancel(token))
-                            .await;
+        match schedule {
+            IntervalSchedule::FixedDelay => A::spawn(async move {
+                let mut sleep = A::sleep(dur);
+                loop {
+                    match select(select(&mut rx_cancel, &mut restart_rx), &mut sleep).await {
+                        // join handle notify to cancel.
+                        Either::Left((Either::Left((Ok(_), _)), _)) => {
+                            let _ = weak_tx
+                                ._send(ActorMessage::IntervalTokenCancel(token))
+                                .await;
+                            return;
+                        }
+                        // join handle is dropped so don't listen to it anymore.
+                        Either::Left((Either::Left((Err(_), _)), s)) => {
+                            s.await;
+                            break;
+                        }
+                        // actor is restarting: the slab entry is already gone, stop.
+                        Either::Left((Either::Right(_), _)) => return,
+                        Either::Right(_) => {
+                            match weak_tx._send(ActorMessage::IntervalToken(token)).await {
+                                Ok(()) => {
+                                    sleep = A::sleep(dur);
+                                    continue;
+                                }
+                                Err(_) => return,
+                            }
+                        }
+                    }
+                }
+
+                // join handle is gone and iter with sleep only, still watching for a restart.
+                loop {
+                    if weak_tx
+                        ._send(ActorMessage::IntervalToken(token))
+                        .await
+                        .is_err()
+                    {
                         return;
                     }
-                    // join handle is dropped so don't listen to it anymore.
-                    Either::Left((Err(_), s)) => {
-                        s.await;
-                        break;
+
+                    match select(&mut restart_rx, A::sleep(dur)).await {
+                        Either::Left(_) => return,
+                        Either::Right(_) => continue,
                     }
-                    Either::Right(_) => {
-                        match weak_tx._send(ActorMessage::IntervalToken(token)).await {
-                            Ok(()) => {
-                                sleep = A::sleep(dur);
-                                continue;
+                }
+            }),
+            IntervalSchedule::FixedRate(missed) => A::spawn(async move {
+                // `deadline` is the absolute instant the next tick is due. Unlike the
+                // fixed-delay schedule it does not get pushed back by handling time.
+                let mut deadline = std::time::Instant::now() + dur;
+
+                loop {
+                    let now = std::time::Instant::now();
+                    let mut sleep = A::sleep(deadline.saturating_duration_since(now));
+                    match select(select(&mut rx_cancel, &mut restart_rx), &mut sleep).await {
+                        // join handle notify to cancel.
+                        Either::Left((Either::Left((Ok(_), _)), _)) => {
+                            let _ = weak_tx
+                                ._send(ActorMessage::IntervalTokenCancel(token))
+                                .await;
+                            return;
+                        }
+                        // join handle is dropped so don't listen to it anymore.
+                        Either::Left((Either::Left((Err(_), _)), s)) => {
+                            s.await;
+                            break;
+                        }
+                        // actor is restarting: the slab entry is already gone, stop.
+                        Either::Left((Either::Right(_), _)) => return,
+                        Either::Right(_) => {
+                            if weak_tx
+                                ._send(ActorMessage::IntervalToken(token))
+                                .await
+                                .is_err()
+                            {
+                                return;
                             }
-                            Err(_) => return,
+
+                            deadline =
+                                match next_fixed_rate_deadline(deadline, dur, missed, &weak_tx, token)
+                                    .await
+                                {
+                                    Some(deadline) => deadline,
+                                    None => return,
+                                };
                         }
                     }
                 }
-            }
 
-            // join handle is gone and iter with sleep only.
-            loop {
-                match weak_tx._send(ActorMessage::IntervalToken(token)).await {
-                    Ok(()) => A::sleep(dur).await,
-                    Err(_) => return,
+                // join handle is gone and iter with sleep only, still watching for a restart.
+                loop {
+                    match select(
+                        &mut restart_rx,
+                        A::sleep(deadline.saturating_duration_since(std::time::Instant::now())),
+                    )
+                    .await
+                    {
+                        Either::Left(_) => return,
+                        Either::Right(_) => (),
+                    }
+
+                    if weak_tx
+                        ._send(ActorMessage::IntervalToken(token))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+
+                    deadline =
+                        match next_fixed_rate_deadline(deadline, dur, missed, &weak_tx, token).await
+                        {
+                            Some(deadline) => deadline,
+                            None => return,
+                        };
                 }
-            }
-        });
+            }),
+        };
 
         ContextJoinHandle { handle: tx_cancel }
     }
 
     fn later(&self, dur: Duration, msg: ActorMessage<A>) -> ContextJoinHandle {
-        let token = self.delay_queue.borrow_mut().insert(msg);
+        // see `interval`'s `restart_cancel` for why this exists: it lets a restart
+        // stop this task instead of it firing into a mailbox nobody re-armed.
+        let (restart_cancel, restart_rx) = oneshot::channel();
+        let token = self
+            .delay_queue
+            .borrow_mut()
+            .insert((msg, restart_cancel));
         let weak_tx = self.tx.clone();
         let (tx_cancel, rx_cancel) = oneshot::channel();
 
         A::spawn(async move {
-            match select(rx_cancel, A::sleep(dur)).await {
-                Either::Left((Ok(_), _)) => {
+            match select(select(rx_cancel, restart_rx), A::sleep(dur)).await {
+                Either::Left((Either::Left((Ok(_), _)), _)) => {
                     let _ = weak_tx._send(ActorMessage::DelayTokenCancel(token)).await;
                     return;
                 }
-                Either::Left((Err(_), s)) => s.await,
+                Either::Left((Either::Left((Err(_), _)), s)) => s.await,
+                // actor is restarting: the slab entry is already gone, stop.
+                Either::Left((Either::Right(_), _)) => return,
                 Either::Right(_) => (),
             }
             let _ = weak_tx._send(ActorMessage::DelayToken(token)).await;
@@ -361,7 +929,7 @@ impl<A: Actor> Context<A> {
             ActorMessage::Ref(msg) => cache_ref.push(msg),
             ActorMessage::DelayToken(token) => {
                 if self.delay_queue.borrow().contains(token) {
-                    let msg = self.delay_queue.borrow_mut().remove(token);
+                    let (msg, _cancel) = self.delay_queue.borrow_mut().remove(token);
                     match msg {
                         ActorMessage::Ref(msg) => cache_ref.push(msg),
                         ActorMessage::Mut(msg) => {
@@ -376,7 +944,7 @@ impl<A: Actor> Context<A> {
             }
             ActorMessage::IntervalToken(token) => {
                 let msg = match self.interval_queue.borrow().get(token) {
-                    Some(msg) => msg.clone_actor_message(),
+                    Some((msg, _cancel)) => msg.clone_actor_message(),
                     None => return false,
                 };
                 match msg {
@@ -414,6 +982,12 @@ pub(crate) struct ContextWithActor<A: Actor> {
     cache_mut: Option<MessageObject<A>>,
     cache_ref: Vec<MessageObject<A>>,
     drop_notify: Option<oneshot::Sender<()>>,
+    // factory captured at `create` time, used by `RestartStrategy::Restart` to build
+    // a fresh actor value instead of resuming the panicked one mid-stream.
+    factory: Option<Box<dyn Fn() -> A>>,
+    // number of restarts performed so far. Survives a panic-recovery because
+    // `Drop::drop` moves the real field values out via `mem::take` before respawning.
+    restarts: usize,
 }
 
 impl<A: Actor> Default for ContextWithActor<A> {
@@ -424,22 +998,95 @@ impl<A: Actor> Default for ContextWithActor<A> {
             cache_mut: None,
             cache_ref: Vec::new(),
             drop_notify: None,
+            factory: None,
+            restarts: 0,
         }
     }
 }
 
+/// policy consulted by [`ContextWithActor`]'s panic recovery, returned from
+/// `Actor::supervise` (defaults to [`RestartStrategy::Resume`], matching the crate's
+/// previous always-resume behavior).
+pub enum RestartStrategy {
+    /// resume the actor mid-stream, reusing whatever state it had before the panic.
+    Resume,
+    /// rebuild the actor from its `create` factory, clearing cached and queued state,
+    /// waiting `backoff` (if any) before re-entering `run`. Once `restarts` reaches
+    /// `max_restarts` the strategy behaves like `Stop`.
+    Restart {
+        max_restarts: usize,
+        backoff: Option<Duration>,
+    },
+    /// give up recovering the actor after a panic.
+    Stop,
+}
+
 impl<A: Actor> Drop for ContextWithActor<A> {
     fn drop(&mut self) {
         // recovery from thread panic.
         if std::thread::panicking() && self.ctx.as_ref().unwrap().state.get() == ActorState::Running
         {
-            let mut ctx = std::mem::take(self);
-            // some of the cached message object may finished gone. remove them.
-            ctx.cache_ref.retain(|m| !m.finished());
+            match A::supervise() {
+                RestartStrategy::Resume => {
+                    let mut ctx = std::mem::take(self);
+                    // some of the cached message object may finished gone. remove them.
+                    ctx.cache_ref.retain(|m| !m.finished());
 
-            A::spawn(async move {
-                let _ = ctx.run().await;
-            });
+                    A::spawn(async move {
+                        let _ = ctx.run().await;
+                    });
+                }
+                RestartStrategy::Restart {
+                    max_restarts,
+                    backoff,
+                } if self.restarts < max_restarts => {
+                    let mut ctx = std::mem::take(self);
+                    ctx.restarts += 1;
+
+                    // a restart gets a clean slate instead of resuming mid-stream:
+                    // drop the cached messages and reset the interval/delay/blocking
+                    // queues, firing each entry's restart-cancel so its background
+                    // task stops instead of leaking (an interval/later task would
+                    // otherwise tick forever into the restarted actor's mailbox with
+                    // a dead token; a blocking job would otherwise deliver a result
+                    // computed under the old, pre-restart state).
+                    ctx.cache_mut = None;
+                    ctx.cache_ref.clear();
+                    if let Some(inner) = ctx.ctx.as_ref() {
+                        for (_, cancel) in inner.interval_queue.borrow_mut().drain() {
+                            let _ = cancel.send(());
+                        }
+                        for (_, cancel) in inner.delay_queue.borrow_mut().drain() {
+                            let _ = cancel.send(());
+                        }
+                        for cancel in inner.blocking_queue.borrow_mut().drain() {
+                            let _ = cancel.send(());
+                        }
+                    }
+                    if let Some(factory) = ctx.factory.as_ref() {
+                        ctx.actor = Some(factory());
+                    }
+
+                    A::spawn(async move {
+                        if let Some(backoff) = backoff {
+                            A::sleep(backoff).await;
+                        }
+
+                        let actor = ctx.actor.as_mut().unwrap();
+                        let inner = ctx.ctx.as_mut().unwrap();
+                        actor.on_restart(inner).await;
+
+                        let _ = ctx.run().await;
+                    });
+                }
+                // `Stop`, or a `Restart` whose budget is exhausted: give up recovering
+                // and let the normal shutdown notification fire below.
+                RestartStrategy::Restart { .. } | RestartStrategy::Stop => {
+                    if let Some(tx) = self.drop_notify.take() {
+                        let _ = tx.send(());
+                    }
+                }
+            }
         } else if let Some(tx) = self.drop_notify.take() {
             let _ = tx.send(());
         }
@@ -447,13 +1094,19 @@ impl<A: Actor> Drop for ContextWithActor<A> {
 }
 
 impl<A: Actor> ContextWithActor<A> {
-    pub(crate) fn new(actor: A, ctx: Context<A>) -> Self {
+    pub(crate) fn new(
+        actor: A,
+        ctx: Context<A>,
+        factory: Option<Box<dyn Fn() -> A>>,
+    ) -> Self {
         Self {
             actor: Some(actor),
             ctx: Some(ctx),
             cache_mut: None,
             cache_ref: Vec::with_capacity(CHANNEL_CAP),
             drop_notify: None,
+            factory,
+            restarts: 0,
         }
     }
 
@@ -467,6 +1120,9 @@ impl<A: Actor> ContextWithActor<A> {
         self.run().await;
     }
 
+    // drives the actor's mailbox. When `Actor::throttle` returns a quantum the loop
+    // batches messages that arrive within it instead of parking after every single
+    // message, trading a bounded latency increase for fewer task wakeups.
     async fn run(&mut self) {
         let actor = self.actor.as_mut().unwrap();
         let ctx = self.ctx.as_mut().unwrap();
@@ -485,49 +1141,124 @@ impl<A: Actor> ContextWithActor<A> {
         }
 
         // batch receive new messages from channel.
-        loop {
+        'main: loop {
+            // while draining after a graceful stop, race every step of message
+            // processing below against the drain deadline (if any) — not just the
+            // idle wait — so a deep backlog or a single wedged handler can't hang
+            // shutdown any longer than `stop_timeout` allows.
+            let deadline = (ctx.state.get() == ActorState::StopGraceful)
+                .then(|| ctx.stop_deadline.get())
+                .flatten();
+
             match ctx.rx.get_mut().try_recv() {
                 Ok(msg) => {
-                    let is_force_stop = ctx
-                        .handle_message(msg, actor, cache_mut, cache_ref, &mut fut, drop_notify)
-                        .await;
-
-                    if is_force_stop {
-                        break;
+                    match handle_with_deadline(
+                        ctx, msg, actor, cache_mut, cache_ref, &mut fut, drop_notify, deadline,
+                    )
+                    .await
+                    {
+                        Processed::Done(true) => break,
+                        Processed::Done(false) => (),
+                        Processed::TimedOut => {
+                            abandon(cache_mut, cache_ref, drop_notify);
+                            break;
+                        }
                     }
                 }
 
                 Err(TryRecvError::Empty) => {
                     // channel is empty. try to handle concurrent messages from previous iters.
-                    ctx.try_handle_concurrent(actor, cache_ref, &mut fut).await;
+                    if try_handle_concurrent_with_deadline(ctx, actor, cache_ref, &mut fut, deadline)
+                        .await
+                    {
+                        abandon(cache_mut, cache_ref, drop_notify);
+                        break;
+                    }
 
                     // block the task and recv one message when channel is empty.
-                    match ctx.rx.get_mut().recv().await {
-                        Some(msg) => {
-                            let is_force_stop = ctx
-                                .handle_message(
-                                    msg,
-                                    actor,
-                                    cache_mut,
-                                    cache_ref,
-                                    &mut fut,
-                                    drop_notify,
-                                )
-                                .await;
+                    match recv_with_deadline::<A>(ctx.rx.get_mut(), deadline).await {
+                        Drain::Msg(msg) => {
+                            match handle_with_deadline(
+                                ctx, msg, actor, cache_mut, cache_ref, &mut fut, drop_notify,
+                                deadline,
+                            )
+                            .await
+                            {
+                                Processed::Done(true) => break,
+                                Processed::TimedOut => {
+                                    abandon(cache_mut, cache_ref, drop_notify);
+                                    break;
+                                }
+                                Processed::Done(false) => {
+                                    // throttled actors trade a bounded latency bump for fewer
+                                    // wakeups: drain everything currently queued in one pass
+                                    // instead of parking again after a single message.
+                                    if let Some(quantum) = A::throttle() {
+                                        let batch_deadline = std::time::Instant::now() + quantum;
+
+                                        loop {
+                                            if std::time::Instant::now() >= batch_deadline {
+                                                break;
+                                            }
+
+                                            match ctx.rx.get_mut().try_recv() {
+                                                Ok(msg) => {
+                                                    match handle_with_deadline(
+                                                        ctx, msg, actor, cache_mut, cache_ref,
+                                                        &mut fut, drop_notify, deadline,
+                                                    )
+                                                    .await
+                                                    {
+                                                        Processed::Done(true) => break 'main,
+                                                        Processed::Done(false) => (),
+                                                        Processed::TimedOut => {
+                                                            abandon(
+                                                                cache_mut, cache_ref, drop_notify,
+                                                            );
+                                                            break 'main;
+                                                        }
+                                                    }
+                                                }
+                                                Err(TryRecvError::Empty) => break,
+                                                Err(TryRecvError::Closed) => {
+                                                    ctx.stop();
+                                                    break;
+                                                }
+                                            }
+                                        }
 
-                            if is_force_stop {
-                                break;
+                                        // the invariant holds across batches too: nothing is
+                                        // left cached once the batch is handed off.
+                                        if try_handle_concurrent_with_deadline(
+                                            ctx, actor, cache_ref, &mut fut, deadline,
+                                        )
+                                        .await
+                                        {
+                                            abandon(cache_mut, cache_ref, drop_notify);
+                                            break 'main;
+                                        }
+                                    }
+                                }
                             }
                         }
-                        None => break,
+                        Drain::Closed => break,
+                        Drain::TimedOut => {
+                            abandon(cache_mut, cache_ref, drop_notify);
+                            break;
+                        }
                     }
                 }
                 Err(TryRecvError::Closed) => {
                     // channel is closed. stop the context.
                     ctx.stop();
                     // try to handle concurrent messages from previous iters.
-                    ctx.try_handle_concurrent(&*actor, cache_ref, &mut fut)
-                        .await;
+                    if try_handle_concurrent_with_deadline(
+                        ctx, &*actor, cache_ref, &mut fut, deadline,
+                    )
+                    .await
+                    {
+                        abandon(cache_mut, cache_ref, drop_notify);
+                    }
 
                     break;
                 }